@@ -0,0 +1,110 @@
+//! Utilities for serializing and deserializing types using their string representations, without
+//! failing the whole deserialization on a parse error.
+//!
+//! This module is a variant of [`crate::to_from_str`] for `Result<T, String>` fields: a value
+//! that fails to parse via [`FromStr`] is kept as `Err(original_string)` instead of aborting
+//! deserialization, so the caller can collect the valid fields of a record and still surface the
+//! offending text for later inspection or reporting. Note that the error variant captures the raw
+//! input string, not the formatted parse-error message, so it round-trips back to the original
+//! text on serialization.
+//!
+//! These functions are intended to be used with Serde's `#[serde(with = "...")]` attribute.
+//!
+//! # Example
+//! ```
+//! use std::net::IpAddr;
+//! use serde::{Serialize, Deserialize};
+//! use serde_json;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Wrapper {
+//!     #[serde(with = "serde_extras::fallible")]
+//!     ip: Result<IpAddr, String>,
+//! }
+//!
+//! let w = Wrapper { ip: Ok("127.0.0.1".parse().unwrap()) };
+//! let json = serde_json::to_string(&w).unwrap();
+//! assert_eq!(json, r#"{"ip":"127.0.0.1"}"#);
+//! let de: Wrapper = serde_json::from_str(&json).unwrap();
+//! assert_eq!(de, w);
+//!
+//! let w_bad = Wrapper { ip: Err("not an ip".to_string()) };
+//! let json_bad = serde_json::to_string(&w_bad).unwrap();
+//! assert_eq!(json_bad, r#"{"ip":"not an ip"}"#);
+//! let de_bad: Wrapper = serde_json::from_str(&json_bad).unwrap();
+//! assert_eq!(de_bad, w_bad);
+//! ```
+
+use std::{marker::PhantomData, str::FromStr};
+
+use serde::{de, Deserializer, Serializer};
+
+struct FallibleVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for FallibleVisitor<T>
+where
+    T: FromStr,
+{
+    type Value = Result<T, String>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(T::from_str(v).map_err(|_| v.to_string()))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&v)
+    }
+}
+
+/// Deserializes a string into `Ok(parsed)` on success, or `Err(original_string)` if the string
+/// cannot be parsed into `T`.
+///
+/// This function is intended to be used with Serde's `#[serde(deserialize_with = "...")]`
+/// attribute. Unlike [`crate::to_from_str::deserialize`], a `FromStr` failure does not abort
+/// deserialization of the surrounding document; the raw, unparsed string is preserved instead.
+///
+/// # Errors
+/// Returns a Serde error only if the input is not a string at all.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Result<T, String>, D::Error>
+where
+    T: FromStr,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(FallibleVisitor(PhantomData))
+}
+
+/// Serializes `Ok(v)` using `v`'s [`ToString`] implementation, and `Err(s)` as the raw string
+/// `s` verbatim.
+///
+/// This function is intended to be used with Serde's `#[serde(serialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error if serialization fails.
+pub fn serialize<S, T>(value: &Result<T, String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ToString,
+    S: Serializer,
+{
+    match value {
+        Ok(v) => serializer.serialize_str(&v.to_string()),
+        Err(s) => serializer.serialize_str(s),
+    }
+}
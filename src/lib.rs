@@ -0,0 +1,17 @@
+//! Serde helper modules for (de)serializing types via their string representations.
+//!
+//! Each module except [`crate::str`] is designed to be used with Serde's `#[serde(with = "...")]`
+//! attribute on a single field. The [`crate::str`] module instead provides the [`Str<T>`] wrapper type,
+//! which implements `Serialize`/`Deserialize` itself and so works inside generic containers.
+
+pub mod fallible;
+pub mod map_values;
+#[cfg(feature = "serde_json")]
+pub mod nested_json;
+pub mod opt_to_from_str;
+pub mod opt_to_from_str_empty;
+pub mod str;
+pub mod to_from_str;
+pub mod vec;
+
+pub use str::Str;
@@ -0,0 +1,101 @@
+//! Utilities for serializing and deserializing map values as strings, where the value type
+//! implements [`ToString`]/[`FromStr`].
+//!
+//! This is the map counterpart to [`crate::vec`]: keys are (de)serialized normally, but each
+//! value is read from / written to a string using its [`FromStr`]/[`ToString`] implementation.
+//! Works with `HashMap<K, V>`, `BTreeMap<K, V>`, or any other map type that can be built from and
+//! iterated as `(K, V)` pairs.
+//!
+//! # Example
+//! ```
+//! use std::collections::HashMap;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Wrapper {
+//!     #[serde(with = "serde_extras::map_values")]
+//!     timeouts: HashMap<String, u64>,
+//! }
+//!
+//! let mut timeouts = HashMap::new();
+//! timeouts.insert("connect".to_string(), 5);
+//! let w = Wrapper { timeouts };
+//! let json = serde_json::to_string(&w).unwrap();
+//! assert_eq!(json, r#"{"timeouts":{"connect":"5"}}"#);
+//! let de: Wrapper = serde_json::from_str(&json).unwrap();
+//! assert_eq!(de, w);
+//! ```
+
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+struct MapValuesVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+impl<'de, M, K, V> de::Visitor<'de> for MapValuesVisitor<M, K, V>
+where
+    M: Default + Extend<(K, V)>,
+    K: Deserialize<'de> + Debug,
+    V: FromStr,
+    V::Err: Display,
+{
+    type Value = M;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of strings")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut out = M::default();
+        while let Some((key, value)) = map.next_entry::<K, String>()? {
+            let value = V::from_str(&value)
+                .map_err(|e| de::Error::custom(format!("invalid value for key {key:?}: {e}")))?;
+            out.extend(std::iter::once((key, value)));
+        }
+        Ok(out)
+    }
+}
+
+/// Deserializes a map from string-valued entries, parsing each value using `V`'s [`FromStr`]
+/// implementation while deserializing keys normally.
+///
+/// This function is intended to be used with Serde's `#[serde(deserialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error, naming the offending key, if any value cannot be parsed into `V`.
+pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Default + Extend<(K, V)>,
+    K: Deserialize<'de> + Debug,
+    V: FromStr,
+    V::Err: Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapValuesVisitor(PhantomData))
+}
+
+/// Serializes a map, writing each value as a string using its [`ToString`] implementation while
+/// serializing keys normally.
+///
+/// This function is intended to be used with Serde's `#[serde(serialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error if serialization fails.
+pub fn serialize<'a, M, K, V, S>(value: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Serialize + 'a,
+    V: ToString + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(value.into_iter().map(|(k, v)| (k, v.to_string())))
+}
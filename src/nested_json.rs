@@ -0,0 +1,117 @@
+//! Utilities for (de)serializing a value embedded as a JSON-encoded string inside an outer
+//! document.
+//!
+//! Some APIs embed a stringified JSON document inside one field of an outer JSON object (a
+//! doubly-encoded payload). This module serializes a value with [`serde_json::to_string`] and
+//! writes the result as a single string field, and deserializes by reading a string field and
+//! running [`serde_json::from_str`] on it.
+//!
+//! This module requires the `serde_json` cargo feature.
+//!
+//! # Example
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Inner {
+//!     a: u32,
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Wrapper {
+//!     #[serde(with = "serde_extras::nested_json")]
+//!     inner: Inner,
+//! }
+//!
+//! let w = Wrapper { inner: Inner { a: 1 } };
+//! let json = serde_json::to_string(&w).unwrap();
+//! assert_eq!(json, r#"{"inner":"{\"a\":1}"}"#);
+//! let de: Wrapper = serde_json::from_str(&json).unwrap();
+//! assert_eq!(de, w);
+//! ```
+
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    ser::Error as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Deserializes a value by reading a string field and parsing it as JSON.
+///
+/// This function is intended to be used with Serde's `#[serde(deserialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error if the field is not a string, or if the string is not valid JSON for
+/// `T`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    serde_json::from_str(&s).map_err(D::Error::custom)
+}
+
+/// Serializes a value as a JSON-encoded string field.
+///
+/// This function is intended to be used with Serde's `#[serde(serialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error if `value` cannot be serialized to JSON.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let s = serde_json::to_string(value).map_err(S::Error::custom)?;
+    serializer.serialize_str(&s)
+}
+
+/// `Option` variant of [`deserialize`]/[`serialize`], for use with `#[serde(with =
+/// "serde_extras::nested_json::option")]` on an `Option<T>` field.
+///
+/// `None` is represented as JSON `null`; `Some(v)` is represented as a JSON-encoded string field,
+/// same as the parent module.
+pub mod option {
+    use serde::{
+        de::{DeserializeOwned, Error as _},
+        ser::Error as _,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// Deserializes an optional value, treating `null` as `None` and a string field as a JSON
+    /// document to parse for `Some`.
+    ///
+    /// # Errors
+    /// Returns a Serde error if the field is neither `null` nor a string, or the string is not
+    /// valid JSON for `T`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        let opt: Option<String> = Deserialize::deserialize(deserializer)?;
+        opt.map(|s| serde_json::from_str(&s).map_err(D::Error::custom))
+            .transpose()
+    }
+
+    /// Serializes `None` as `null` and `Some(v)` as a JSON-encoded string field.
+    ///
+    /// # Errors
+    /// Returns a Serde error if `value` cannot be serialized to JSON.
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => {
+                let s = serde_json::to_string(v).map_err(S::Error::custom)?;
+                serializer.serialize_str(&s)
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+}
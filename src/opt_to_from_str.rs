@@ -34,9 +34,48 @@
 //! assert_eq!(de_none, w_none);
 //! ```
 
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
 
-use serde::{Deserialize, Deserializer, Serializer};
+use serde::{de, Deserializer, Serializer};
+
+use crate::to_from_str::FromStrVisitor;
+
+struct OptFromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for OptFromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_str(FromStrVisitor(PhantomData))
+            .map(Some)
+    }
+}
 
 /// Deserializes an optional value from a string using its [`FromStr`] implementation.
 ///
@@ -44,6 +83,10 @@ use serde::{Deserialize, Deserializer, Serializer};
 /// It attempts to parse the input string into the target type `Option<T>`. If parsing fails, a Serde error
 /// is returned.
 ///
+/// Unlike a naive `Deserialize::deserialize` into `Option<&str>`, this accepts strings from any
+/// `Deserializer`, including formats that cannot borrow the string data (e.g. formats with escape
+/// processing, MessagePack, or bincode).
+///
 /// # Errors
 /// Returns a Serde error if the input string cannot be parsed into the target type.
 pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
@@ -52,11 +95,7 @@ where
     T::Err: Display,
     D: Deserializer<'de>,
 {
-    let opt: Option<&str> = Deserialize::deserialize(deserializer)?;
-    match opt {
-        Some(s) => T::from_str(s).map(Some).map_err(serde::de::Error::custom),
-        None => Ok(None),
-    }
+    deserializer.deserialize_option(OptFromStrVisitor(PhantomData))
 }
 
 /// Serializes an optional value to a string using its [`ToString`] implementation.
@@ -0,0 +1,121 @@
+//! Utilities for serializing and deserializing `Option<T>` using an empty string as `None`.
+//!
+//! This module is a sibling to [`crate::opt_to_from_str`], but matches the common CSV/form/legacy
+//! JSON convention where an absent value is represented as an empty string `""` rather than
+//! `null`. These functions are intended to be used with Serde's `#[serde(with = "...")]`
+//! attribute to enable (de)serialization via string conversion.
+//!
+//! # Note
+//! The `ToString` and `FromStr` implementations for a type must be true inverses of each other
+//! for correct round-trip serialization and deserialization. If this is not the case, data loss
+//! or errors may occur.
+//!
+//! Additionally, because an empty string is used as the `None` sentinel, this module is not
+//! suitable for types `T` where `Some(v)` can have `v.to_string()` equal to `""`: such a value
+//! would serialize identically to `None` and deserialize back as `None`, silently losing data.
+//!
+//! # Example
+//! ```
+//! use std::net::IpAddr;
+//! use serde::{Serialize, Deserialize};
+//! use serde_json;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Wrapper {
+//!     #[serde(with = "serde_extras::opt_to_from_str_empty")]
+//!     ip: Option<IpAddr>,
+//! }
+//!
+//! let w = Wrapper { ip: Some("127.0.0.1".parse().unwrap()) };
+//! let json = serde_json::to_string(&w).unwrap();
+//! assert_eq!(json, r#"{"ip":"127.0.0.1"}"#);
+//! let de: Wrapper = serde_json::from_str(&json).unwrap();
+//! assert_eq!(de, w);
+//! let w_none = Wrapper { ip: None };
+//! let json_none = serde_json::to_string(&w_none).unwrap();
+//! assert_eq!(json_none, r#"{"ip":""}"#);
+//! let de_none: Wrapper = serde_json::from_str(&json_none).unwrap();
+//! assert_eq!(de_none, w_none);
+//! ```
+
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use serde::{de, Deserializer, Serializer};
+
+struct EmptyAsNoneVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for EmptyAsNoneVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string, empty for none")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.is_empty() {
+            Ok(None)
+        } else {
+            T::from_str(v).map(Some).map_err(de::Error::custom)
+        }
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&v)
+    }
+}
+
+/// Deserializes an optional value from a string, treating an empty string as `None`.
+///
+/// This function is intended to be used with Serde's `#[serde(deserialize_with = "...")]`
+/// attribute. A non-empty input string is parsed using the target type's [`FromStr`]
+/// implementation; an empty string yields `None`.
+///
+/// Unlike a naive `Deserialize::deserialize` into `&str`, this accepts strings from any
+/// `Deserializer`, including formats that cannot borrow the string data (e.g. formats with escape
+/// processing, MessagePack, or bincode).
+///
+/// # Errors
+/// Returns a Serde error if a non-empty input string cannot be parsed into the target type.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(EmptyAsNoneVisitor(PhantomData))
+}
+
+/// Serializes an optional value to a string, writing `None` as an empty string.
+///
+/// This function is intended to be used with Serde's `#[serde(serialize_with = "...")]`
+/// attribute. `Some(v)` is serialized as `v.to_string()`; `None` is serialized as `""`.
+///
+/// # Errors
+/// Returns a Serde error if serialization fails.
+pub fn serialize<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ToString,
+    S: Serializer,
+{
+    match value {
+        Some(v) => serializer.serialize_str(&v.to_string()),
+        None => serializer.serialize_str(""),
+    }
+}
@@ -0,0 +1,84 @@
+//! A wrapper type that (de)serializes its inner value via [`crate::to_from_str`].
+//!
+//! [`Str<T>`] exists for the cases where `#[serde(with = "...")]` field attributes cannot reach:
+//! inside generic containers such as `Vec<Str<IpAddr>>`, `HashMap<String, Str<Duration>>`, or any
+//! other place where serde derives (de)serialization generically rather than per-field.
+
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A transparent wrapper around `T` that (de)serializes via `T`'s [`FromStr`]/[`Display`]
+/// implementations, using the same string-conversion logic as [`crate::to_from_str`].
+///
+/// `Str<T>` derefs to `T`, so it composes with code written against `T` while still being usable
+/// anywhere serde needs a type that implements `Serialize`/`Deserialize` on its own, such as
+/// inside a `Vec` or as a `HashMap` value.
+///
+/// # Example
+/// ```
+/// use std::net::IpAddr;
+/// use serde_extras::Str;
+///
+/// let ips: Vec<Str<IpAddr>> = serde_json::from_str(r#"["127.0.0.1", "::1"]"#).unwrap();
+/// assert_eq!(*ips[0], "127.0.0.1".parse::<IpAddr>().unwrap());
+/// assert_eq!(serde_json::to_string(&ips).unwrap(), r#"["127.0.0.1","::1"]"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Str<T>(pub T);
+
+impl<T> Str<T> {
+    /// Unwraps the wrapper, returning the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Str<T> {
+    fn from(value: T) -> Self {
+        Str(value)
+    }
+}
+
+impl<T> Deref for Str<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Str<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Serialize for Str<T>
+where
+    T: ToString,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::to_from_str::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Str<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::to_from_str::deserialize(deserializer).map(Str)
+    }
+}
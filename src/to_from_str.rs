@@ -28,9 +28,44 @@
 //! assert_eq!(de, w);
 //! ```
 
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
 
-use serde::{Deserialize, Deserializer, Serializer};
+use serde::{de, Deserializer, Serializer};
+
+pub(crate) struct FromStrVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_str(v).map_err(de::Error::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(v)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&v)
+    }
+}
 
 /// Deserializes a value from a string using its [`FromStr`] implementation.
 ///
@@ -38,6 +73,10 @@ use serde::{Deserialize, Deserializer, Serializer};
 /// It attempts to parse the input string into the target type `T`. If parsing fails, a Serde error
 /// is returned.
 ///
+/// Unlike a naive `Deserialize::deserialize` into `&str`, this accepts strings from any
+/// `Deserializer`, including formats that cannot borrow the string data (e.g. formats with escape
+/// processing, MessagePack, or bincode).
+///
 /// # Errors
 /// Returns a Serde error if the input string cannot be parsed into the target type.
 pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
@@ -46,8 +85,7 @@ where
     T::Err: Display,
     D: Deserializer<'de>,
 {
-    let s: &str = Deserialize::deserialize(deserializer)?;
-    T::from_str(s).map_err(serde::de::Error::custom)
+    deserializer.deserialize_str(FromStrVisitor(PhantomData))
 }
 
 /// Serializes a value to a string using its [`ToString`] implementation.
@@ -0,0 +1,94 @@
+//! Utilities for serializing and deserializing a `Vec<T>` as a JSON array of strings, where `T`
+//! implements [`ToString`]/[`FromStr`].
+//!
+//! `#[serde(with = "serde_extras::to_from_str")]` only targets a single scalar field; this module
+//! extends the same string-conversion logic element-wise to a `Vec<T>`, e.g. `Vec<IpAddr>`.
+//!
+//! # Example
+//! ```
+//! use std::net::IpAddr;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Wrapper {
+//!     #[serde(with = "serde_extras::vec")]
+//!     ips: Vec<IpAddr>,
+//! }
+//!
+//! let w = Wrapper { ips: vec!["127.0.0.1".parse().unwrap(), "::1".parse().unwrap()] };
+//! let json = serde_json::to_string(&w).unwrap();
+//! assert_eq!(json, r#"{"ips":["127.0.0.1","::1"]}"#);
+//! let de: Wrapper = serde_json::from_str(&json).unwrap();
+//! assert_eq!(de, w);
+//! ```
+
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use serde::{de, ser::SerializeSeq, Deserializer, Serializer};
+
+struct VecVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for VecVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence of strings")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        let mut index = 0usize;
+        while let Some(s) = seq.next_element::<String>()? {
+            let value = T::from_str(&s).map_err(|e| {
+                de::Error::custom(format!("invalid value at index {index}: {e}"))
+            })?;
+            out.push(value);
+            index += 1;
+        }
+        Ok(out)
+    }
+}
+
+/// Deserializes a `Vec<T>` from a sequence of strings, parsing each element using `T`'s
+/// [`FromStr`] implementation.
+///
+/// This function is intended to be used with Serde's `#[serde(deserialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error, naming the offending index, if any element cannot be parsed into `T`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(VecVisitor(PhantomData))
+}
+
+/// Serializes a `Vec<T>` as a sequence of strings, using each element's [`ToString`]
+/// implementation.
+///
+/// This function is intended to be used with Serde's `#[serde(serialize_with = "...")]`
+/// attribute.
+///
+/// # Errors
+/// Returns a Serde error if serialization fails.
+pub fn serialize<S, T>(value: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ToString,
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(value.len()))?;
+    for v in value {
+        seq.serialize_element(&v.to_string())?;
+    }
+    seq.end()
+}